@@ -0,0 +1,102 @@
+use solana_program::{
+    account_info::AccountInfo, ed25519_program, program_error::ProgramError, pubkey::Pubkey,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+const ED25519_HEADER_LEN: usize = 2; // num_signatures:u8 + padding:u8
+const ED25519_OFFSETS_LEN: usize = 14; // 7 little-endian u16 fields
+const PUBKEY_LEN: usize = 32;
+
+/// Scans the instructions that precede the current one in this transaction
+/// for `Ed25519Program` entries attesting to `expected_message`, and counts
+/// how many *distinct* `configured_oracles` produced one.
+///
+/// The Ed25519 precompile has already checked each signature by the time this
+/// program runs, so we only need to confirm a matching instruction exists and
+/// cross-check its embedded pubkey and message against our expectations.
+pub fn count_verified_attestations(
+    instructions_sysvar_info: &AccountInfo,
+    expected_message: &[u8],
+    configured_oracles: &[Pubkey],
+) -> Result<u8, ProgramError> {
+    let mut approved: Vec<Pubkey> = Vec::new();
+    let current_index = load_current_index_checked(instructions_sysvar_info)? as usize;
+
+    for index in 0..current_index {
+        let instruction = load_instruction_at_checked(index, instructions_sysvar_info)?;
+        if instruction.program_id == ed25519_program::ID {
+            collect_matching_signers(
+                &instruction.data,
+                index as u16,
+                expected_message,
+                configured_oracles,
+                &mut approved,
+            );
+        }
+    }
+
+    Ok(approved.len() as u8)
+}
+
+fn collect_matching_signers(
+    data: &[u8],
+    self_index: u16,
+    expected_message: &[u8],
+    configured_oracles: &[Pubkey],
+    approved: &mut Vec<Pubkey>,
+) {
+    if data.is_empty() {
+        return;
+    }
+    let num_signatures = data[0] as usize;
+
+    for i in 0..num_signatures {
+        let offsets_start = ED25519_HEADER_LEN + i * ED25519_OFFSETS_LEN;
+        if offsets_start + ED25519_OFFSETS_LEN > data.len() {
+            return;
+        }
+
+        let public_key_offset = read_u16(data, offsets_start + 4);
+        let public_key_instruction_index = read_u16(data, offsets_start + 6);
+        let message_data_offset = read_u16(data, offsets_start + 8);
+        let message_data_size = read_u16(data, offsets_start + 10);
+        let message_instruction_index = read_u16(data, offsets_start + 12);
+
+        // Only accept attestations whose pubkey and message live in this same
+        // Ed25519Program instruction, i.e. not borrowed from elsewhere in the
+        // tx. Instruction-builder tooling (e.g. `new_ed25519_instruction`)
+        // encodes "this same instruction" as u16::MAX rather than the literal
+        // index, so accept either form.
+        if !is_self_or_sentinel(public_key_instruction_index, self_index)
+            || !is_self_or_sentinel(message_instruction_index, self_index)
+        {
+            continue;
+        }
+
+        let pk_start = public_key_offset as usize;
+        let pk_end = pk_start + PUBKEY_LEN;
+        let msg_start = message_data_offset as usize;
+        let msg_end = msg_start + message_data_size as usize;
+        if pk_end > data.len() || msg_end > data.len() {
+            continue;
+        }
+
+        if data[msg_start..msg_end] != *expected_message {
+            continue;
+        }
+
+        let pubkey = Pubkey::try_from(&data[pk_start..pk_end]).unwrap_or_default();
+        if !configured_oracles.contains(&pubkey) || approved.contains(&pubkey) {
+            continue;
+        }
+        approved.push(pubkey);
+    }
+}
+
+fn is_self_or_sentinel(instruction_index: u16, self_index: u16) -> bool {
+    instruction_index == self_index || instruction_index == u16::MAX
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([data[offset], data[offset + 1]])
+}
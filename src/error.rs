@@ -23,6 +23,42 @@ pub enum ProxyError {
     
     #[error("Invalid PDA derivation")]
     InvalidPDA,
+
+    #[error("Multiplier history account is full")]
+    HistoryFull,
+
+    #[error("Invalid multiplier history account")]
+    InvalidHistoryAccount,
+
+    #[error("No authority handoff is pending")]
+    NoPendingAuthority,
+
+    #[error("Token program does not match the one configured at initialization")]
+    InvalidTokenProgram,
+
+    #[error("Multiplier change exceeds the configured maximum relative change")]
+    MultiplierChangeTooLarge,
+
+    #[error("Not enough time has elapsed since the last multiplier update")]
+    UpdateTooSoon,
+
+    #[error("Fewer valid signers than the configured multisig threshold")]
+    NotEnoughSigners,
+
+    #[error("The same signer was counted towards the multisig threshold twice")]
+    DuplicateSigner,
+
+    #[error("Signer is not part of the configured multisig")]
+    UnknownSigner,
+
+    #[error("Fewer valid oracle attestations than the configured threshold")]
+    InsufficientAttestations,
+
+    #[error("Attestation nonce is not greater than the last accepted nonce")]
+    StaleNonce,
+
+    #[error("Attestation is malformed or does not match the expected message")]
+    BadAttestation,
 }
 
 impl From<ProxyError> for ProgramError {
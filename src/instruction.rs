@@ -10,10 +10,20 @@ pub enum ProxyInstruction {
     /// 1. `[writable]` State account (PDA)
     /// 2. `[]` Authority PDA
     /// 3. `[]` Token mint
-    /// 4. `[]` System program
+    /// 4. `[]` Token program implementing the Scaled UI Amount interface
+    /// 5. `[]` System program
     Initialize {
         /// Initial authority for the proxy
         authority: Pubkey,
+        /// Multiplier the mint is assumed to already be at, cached as the
+        /// guardrail baseline for the first `UpdateMultiplier` call
+        initial_multiplier: f64,
+        /// Maximum allowed relative change per update, in basis points; 0 disables the check
+        max_relative_change_bps: u16,
+        /// Minimum number of seconds required between two updates; 0 disables the check
+        min_update_interval_secs: i64,
+        /// When true, the multiplier may only ever increase
+        monotonic_only: bool,
     },
 
     /// Update the token multiplier via CPI to Token-2022
@@ -23,7 +33,10 @@ pub enum ProxyInstruction {
     /// 1. `[writable]` State account (PDA)
     /// 2. `[]` Authority PDA
     /// 3. `[writable]` Token mint
-    /// 4. `[]` Token-2022 program
+    /// 4. `[]` Token program (must match `ProxyState::token_program`)
+    /// 5. `[]` Multisig account (PDA), required when `ProxyState::multisig_enabled`
+    /// 6. `[signer]` Additional multisig co-signers, only when multisig is enabled (variable count)
+    /// 7. `[writable]` History account (PDA), optional — appends an audit entry when present
     UpdateMultiplier {
         /// New multiplier (must be > 1.0)
         new_multiplier: f64,
@@ -31,13 +44,125 @@ pub enum ProxyInstruction {
         effective_timestamp: i64,
     },
 
-    /// Update the program authority
+    /// Nominate a new program authority. The nominee must accept via
+    /// `AcceptAuthority` before control actually transfers.
     ///
     /// Accounts:
     /// 0. `[signer]` Current authority
     /// 1. `[writable]` State account (PDA)
     UpdateAuthority {
-        /// New authority
+        /// Authority nominated to take over
         new_authority: Pubkey,
     },
+
+    /// Create and size the multiplier history ring buffer account
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` Payer
+    /// 1. `[signer]` Current authority
+    /// 2. `[]` State account (PDA)
+    /// 3. `[writable]` History account (PDA)
+    /// 4. `[]` System program
+    InitializeHistory {
+        /// Number of entries the ring buffer can hold
+        capacity: u32,
+    },
+
+    /// Accept a pending authority nomination. Must be signed by the
+    /// nominated key itself, which then becomes the active authority.
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Pending (nominated) authority
+    /// 1. `[writable]` State account (PDA)
+    AcceptAuthority,
+
+    /// Tune the multiplier-update guardrails
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Current authority
+    /// 1. `[writable]` State account (PDA)
+    UpdateGuardrails {
+        /// Maximum allowed relative change per update, in basis points; 0 disables the check
+        max_relative_change_bps: u16,
+        /// Minimum number of seconds required between two updates; 0 disables the check
+        min_update_interval_secs: i64,
+        /// When true, the multiplier may only ever increase
+        monotonic_only: bool,
+    },
+
+    /// Switch `UpdateMultiplier` from single-authority to M-of-N multisig
+    /// governance
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` Payer
+    /// 1. `[signer]` Current authority
+    /// 2. `[]` State account (PDA)
+    /// 3. `[writable]` Multisig account (PDA)
+    /// 4. `[]` System program
+    InitializeMultisig {
+        /// Number of distinct signers required to approve an update
+        threshold: u8,
+        /// Pubkeys authorized to co-sign `UpdateMultiplier`
+        signers: Vec<Pubkey>,
+    },
+
+    /// Initialize a mint's Scaled UI Amount extension with the proxy's
+    /// authority PDA as its multiplier authority, and point this proxy at
+    /// that mint with `initial_multiplier` as the starting guardrail
+    /// baseline.
+    ///
+    /// This repoints `ProxyState::token_mint`/`current_multiplier` under a
+    /// single `authority` signature with no guardrail check, so it is
+    /// rejected once `ProxyState::multisig_enabled` is set — otherwise a
+    /// lone authority key could bypass the multisig quorum `UpdateMultiplier`
+    /// is otherwise gated behind.
+    ///
+    /// Accounts:
+    /// 0. `[signer]` Current authority
+    /// 1. `[writable]` State account (PDA)
+    /// 2. `[]` Authority PDA
+    /// 3. `[writable]` Token mint
+    /// 4. `[]` Token program
+    InitializeScaledUiMint {
+        /// Multiplier the mint starts at
+        initial_multiplier: f64,
+    },
+
+    /// Configure the set of oracle keys and threshold for
+    /// `SubmitAttestedMultiplier`
+    ///
+    /// Accounts:
+    /// 0. `[writable, signer]` Payer
+    /// 1. `[signer]` Current authority
+    /// 2. `[]` State account (PDA)
+    /// 3. `[writable]` Oracle config account (PDA)
+    /// 4. `[]` System program
+    InitializeOracleConfig {
+        /// Number of distinct oracle attestations required per update
+        threshold: u8,
+        /// Ed25519 pubkeys authorized to attest multiplier updates
+        oracles: Vec<Pubkey>,
+    },
+
+    /// Update the multiplier using an off-chain oracle attestation instead of
+    /// an on-chain authority signature. The attestation is verified by
+    /// cross-checking `Ed25519Program` instructions present earlier in the
+    /// same transaction against the configured oracle set.
+    ///
+    /// Accounts:
+    /// 0. `[]` Instructions sysvar
+    /// 1. `[writable]` State account (PDA)
+    /// 2. `[]` Authority PDA
+    /// 3. `[writable]` Token mint
+    /// 4. `[]` Token program
+    /// 5. `[writable]` Oracle config account (PDA)
+    /// 6. `[writable]` History account (PDA), optional — appends an audit entry when present
+    SubmitAttestedMultiplier {
+        /// New multiplier, must match the attested message
+        multiplier: f64,
+        /// Unix timestamp when the multiplier becomes effective
+        effective_timestamp: i64,
+        /// Strictly-increasing replay-protection nonce
+        nonce: u64,
+    },
 }
\ No newline at end of file
@@ -1,15 +1,22 @@
-use crate::{error::ProxyError, instruction::ProxyInstruction, state::ProxyState, token_2022_helpers};
+use crate::{
+    ed25519_helpers,
+    error::ProxyError,
+    instruction::ProxyInstruction,
+    state::{HistoryEntry, HistoryHeader, MultisigConfig, OracleConfig, ProxyState},
+    token_2022_helpers,
+};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    clock::Clock,
     entrypoint::ProgramResult,
     msg,
-    program::{invoke_signed},
+    program::{invoke, invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
     rent::Rent,
     system_instruction,
-    sysvar::Sysvar,
+    sysvar::{instructions::ID as INSTRUCTIONS_SYSVAR_ID, Sysvar},
 };
 
 pub struct Processor;
@@ -22,9 +29,21 @@ impl Processor {
     ) -> ProgramResult {
         let instruction = ProxyInstruction::try_from_slice(instruction_data)?;
         match instruction {
-            ProxyInstruction::Initialize { authority } => {
-                Self::process_initialize(program_id, accounts, authority)
-            }
+            ProxyInstruction::Initialize {
+                authority,
+                initial_multiplier,
+                max_relative_change_bps,
+                min_update_interval_secs,
+                monotonic_only,
+            } => Self::process_initialize(
+                program_id,
+                accounts,
+                authority,
+                initial_multiplier,
+                max_relative_change_bps,
+                min_update_interval_secs,
+                monotonic_only,
+            ),
             ProxyInstruction::UpdateMultiplier {
                 new_multiplier,
                 effective_timestamp,
@@ -37,6 +56,43 @@ impl Processor {
             ProxyInstruction::UpdateAuthority { new_authority } => {
                 Self::process_update_authority(program_id, accounts, new_authority)
             }
+            ProxyInstruction::InitializeHistory { capacity } => {
+                Self::process_initialize_history(program_id, accounts, capacity)
+            }
+            ProxyInstruction::AcceptAuthority => {
+                Self::process_accept_authority(program_id, accounts)
+            }
+            ProxyInstruction::UpdateGuardrails {
+                max_relative_change_bps,
+                min_update_interval_secs,
+                monotonic_only,
+            } => Self::process_update_guardrails(
+                program_id,
+                accounts,
+                max_relative_change_bps,
+                min_update_interval_secs,
+                monotonic_only,
+            ),
+            ProxyInstruction::InitializeMultisig { threshold, signers } => {
+                Self::process_initialize_multisig(program_id, accounts, threshold, signers)
+            }
+            ProxyInstruction::InitializeScaledUiMint { initial_multiplier } => {
+                Self::process_initialize_scaled_ui_mint(program_id, accounts, initial_multiplier)
+            }
+            ProxyInstruction::InitializeOracleConfig { threshold, oracles } => {
+                Self::process_initialize_oracle_config(program_id, accounts, threshold, oracles)
+            }
+            ProxyInstruction::SubmitAttestedMultiplier {
+                multiplier,
+                effective_timestamp,
+                nonce,
+            } => Self::process_submit_attested_multiplier(
+                program_id,
+                accounts,
+                multiplier,
+                effective_timestamp,
+                nonce,
+            ),
         }
     }
 
@@ -44,12 +100,17 @@ impl Processor {
         program_id: &Pubkey,
         accounts: &[AccountInfo],
         authority: Pubkey,
+        initial_multiplier: f64,
+        max_relative_change_bps: u16,
+        min_update_interval_secs: i64,
+        monotonic_only: bool,
     ) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
         let payer_info = next_account_info(account_info_iter)?;
         let state_info = next_account_info(account_info_iter)?;
         let authority_pda_info = next_account_info(account_info_iter)?;
         let token_mint_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
         let system_program_info = next_account_info(account_info_iter)?;
 
         // Verify payer is signer
@@ -83,6 +144,9 @@ impl Processor {
             }
         }
 
+        // Verify the baseline multiplier
+        try_validate_multiplier(initial_multiplier)?;
+
         // Create state account
         let rent = Rent::get()?;
         let space = ProxyState::LEN;
@@ -108,6 +172,14 @@ impl Processor {
             authority,
             token_mint: *token_mint_info.key,
             bump: authority_bump,
+            pending_authority: Pubkey::default(),
+            token_program: *token_program_info.key,
+            current_multiplier: initial_multiplier,
+            last_update_timestamp: 0,
+            max_relative_change_bps,
+            min_update_interval_secs,
+            monotonic_only,
+            multisig_enabled: false,
         };
 
         state.serialize(&mut *state_info.data.borrow_mut())?;
@@ -115,6 +187,7 @@ impl Processor {
         msg!("Proxy initialized with authority: {}", authority);
         msg!("Authority PDA: {}", authority_pda);
         msg!("Token mint: {}", token_mint_info.key);
+        msg!("Token program: {}", token_program_info.key);
 
         Ok(())
     }
@@ -130,7 +203,8 @@ impl Processor {
         let state_info = next_account_info(account_info_iter)?;
         let authority_pda_info = next_account_info(account_info_iter)?;
         let token_mint_info = next_account_info(account_info_iter)?;
-        let _token_program_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
+        let remaining: Vec<&AccountInfo> = account_info_iter.collect();
 
         // Verify authority is signer
         if !authority_info.is_signer {
@@ -138,13 +212,41 @@ impl Processor {
         }
 
         // Load and verify state
-        let state = ProxyState::try_from_slice(&state_info.data.borrow())?;
+        let mut state = ProxyState::try_from_slice(&state_info.data.borrow())?;
         if !state.initialized {
             return Err(ProxyError::NotInitialized.into());
         }
 
-        // Verify authority
-        if state.authority != *authority_info.key {
+        // Split the remaining accounts into the optional history account
+        // (identified by its PDA) and, in multisig mode, extra co-signers
+        let (history_pda, _) =
+            Pubkey::find_program_address(&[HistoryHeader::HISTORY_SEED], program_id);
+        let mut remaining_iter = remaining.into_iter();
+        let multisig_info = if state.multisig_enabled {
+            Some(next_account_info(&mut remaining_iter)?)
+        } else {
+            None
+        };
+        let mut history_info = None;
+        let mut signer_candidates: Vec<&AccountInfo> = vec![authority_info];
+        for info in remaining_iter {
+            if *info.key == history_pda {
+                history_info = Some(info);
+            } else {
+                signer_candidates.push(info);
+            }
+        }
+
+        // Verify authority, either the single configured key or a multisig quorum
+        if let Some(multisig_info) = multisig_info {
+            let (multisig_pda, _) =
+                Pubkey::find_program_address(&[MultisigConfig::MULTISIG_SEED], program_id);
+            if multisig_pda != *multisig_info.key {
+                return Err(ProxyError::InvalidPDA.into());
+            }
+            let config = MultisigConfig::try_from_slice(&multisig_info.data.borrow())?;
+            Self::verify_multisig_quorum(&config, &signer_candidates)?;
+        } else if state.authority != *authority_info.key {
             return Err(ProxyError::InvalidAuthority.into());
         }
 
@@ -153,9 +255,18 @@ impl Processor {
             return Err(ProxyError::InvalidMint.into());
         }
 
+        // Verify token program matches the one configured at Initialize
+        if state.token_program != *token_program_info.key {
+            return Err(ProxyError::InvalidTokenProgram.into());
+        }
+
         // Verify multiplier
         try_validate_multiplier(new_multiplier)?;
 
+        // Enforce the configured guardrails against the real on-chain clock
+        let now = Clock::get()?.unix_timestamp;
+        Self::enforce_guardrails(&state, new_multiplier, now)?;
+
         // Verify authority PDA
         let (authority_pda, authority_bump) = Pubkey::find_program_address(
             &[ProxyState::AUTHORITY_SEED],
@@ -165,8 +276,10 @@ impl Processor {
             return Err(ProxyError::InvalidPDA.into());
         }
 
-        // Create Token-2022 update multiplier instruction using our helper
+        // Create the update multiplier instruction using our helper, targeting
+        // whichever token program this proxy was configured for
         let update_ix = token_2022_helpers::update_multiplier(
+            token_program_info.key,
             token_mint_info.key,
             authority_pda_info.key,
             new_multiplier,
@@ -182,6 +295,331 @@ impl Processor {
 
         msg!("Updated multiplier to {} effective at timestamp {}", new_multiplier, effective_timestamp);
 
+        // Cache the new multiplier and the real acceptance time so the next
+        // guardrail check has a baseline that the caller can't back-date
+        state.current_multiplier = new_multiplier;
+        state.last_update_timestamp = now;
+        state.serialize(&mut *state_info.data.borrow_mut())?;
+
+        // Append an audit entry to the history ring buffer, if one was supplied
+        if let Some(history_info) = history_info {
+            Self::record_history_entry(
+                program_id,
+                history_info,
+                new_multiplier,
+                effective_timestamp,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn verify_multisig_quorum(
+        config: &MultisigConfig,
+        candidates: &[&AccountInfo],
+    ) -> ProgramResult {
+        let mut approved: Vec<Pubkey> = Vec::new();
+        for info in candidates {
+            if !info.is_signer {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            if !config.signers.contains(info.key) {
+                return Err(ProxyError::UnknownSigner.into());
+            }
+            if approved.contains(info.key) {
+                return Err(ProxyError::DuplicateSigner.into());
+            }
+            approved.push(*info.key);
+        }
+
+        if (approved.len() as u8) < config.threshold {
+            return Err(ProxyError::NotEnoughSigners.into());
+        }
+
+        Ok(())
+    }
+
+    fn process_initialize_multisig(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        threshold: u8,
+        signers: Vec<Pubkey>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let payer_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+        let state_info = next_account_info(account_info_iter)?;
+        let multisig_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        if !payer_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if !authority_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Load and verify state
+        let mut state = ProxyState::try_from_slice(&state_info.data.borrow())?;
+        if !state.initialized {
+            return Err(ProxyError::NotInitialized.into());
+        }
+        if state.authority != *authority_info.key {
+            return Err(ProxyError::InvalidAuthority.into());
+        }
+
+        // Reject configurations that could never reach quorum, and duplicate signers
+        if threshold == 0
+            || signers.is_empty()
+            || (threshold as usize) > signers.len()
+            || signers.len() > MultisigConfig::MAX_SIGNERS
+        {
+            return Err(ProxyError::NotEnoughSigners.into());
+        }
+        for i in 0..signers.len() {
+            for j in (i + 1)..signers.len() {
+                if signers[i] == signers[j] {
+                    return Err(ProxyError::DuplicateSigner.into());
+                }
+            }
+        }
+
+        // Derive and verify multisig PDA
+        let (multisig_pda, multisig_bump) =
+            Pubkey::find_program_address(&[MultisigConfig::MULTISIG_SEED], program_id);
+        if multisig_pda != *multisig_info.key {
+            return Err(ProxyError::InvalidPDA.into());
+        }
+        if multisig_info.data_len() > 0 {
+            return Err(ProxyError::AlreadyInitialized.into());
+        }
+
+        // Create and size the multisig account
+        let rent = Rent::get()?;
+        let space = MultisigConfig::space(signers.len());
+        let lamports = rent.minimum_balance(space);
+
+        let create_account_ix = system_instruction::create_account(
+            payer_info.key,
+            multisig_info.key,
+            lamports,
+            space as u64,
+            program_id,
+        );
+
+        invoke_signed(
+            &create_account_ix,
+            &[payer_info.clone(), multisig_info.clone(), system_program_info.clone()],
+            &[&[MultisigConfig::MULTISIG_SEED, &[multisig_bump]]],
+        )?;
+
+        let signer_count = signers.len();
+        let config = MultisigConfig { threshold, signers };
+        config.serialize(&mut *multisig_info.data.borrow_mut())?;
+
+        state.multisig_enabled = true;
+        state.serialize(&mut *state_info.data.borrow_mut())?;
+
+        msg!("Multisig initialized with threshold {} of {} signers", threshold, signer_count);
+
+        Ok(())
+    }
+
+    fn record_history_entry(
+        program_id: &Pubkey,
+        history_info: &AccountInfo,
+        multiplier: f64,
+        effective_timestamp: i64,
+    ) -> ProgramResult {
+        let (history_pda, _) =
+            Pubkey::find_program_address(&[HistoryHeader::HISTORY_SEED], program_id);
+        if history_pda != *history_info.key {
+            return Err(ProxyError::InvalidHistoryAccount.into());
+        }
+
+        if history_info.data_len() < HistoryHeader::LEN {
+            return Err(ProxyError::InvalidHistoryAccount.into());
+        }
+
+        let mut header =
+            HistoryHeader::try_from_slice(&history_info.data.borrow()[..HistoryHeader::LEN])?;
+        if header.capacity == 0 {
+            return Err(ProxyError::InvalidHistoryAccount.into());
+        }
+
+        let entry = HistoryEntry {
+            multiplier,
+            effective_timestamp,
+            recorded_slot: Clock::get()?.slot,
+        };
+
+        let offset =
+            HistoryHeader::LEN + (header.head as usize % header.capacity as usize) * HistoryEntry::LEN;
+        {
+            let mut data = history_info.data.borrow_mut();
+            entry.serialize(&mut &mut data[offset..offset + HistoryEntry::LEN])?;
+        }
+
+        header.head = (header.head + 1) % header.capacity;
+        header.entry_count = header.entry_count.saturating_add(1).min(header.capacity);
+        header.serialize(&mut *history_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    fn process_initialize_history(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        capacity: u32,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let payer_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+        let state_info = next_account_info(account_info_iter)?;
+        let history_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        // Verify payer is signer
+        if !payer_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if !authority_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Load and verify state
+        let state = ProxyState::try_from_slice(&state_info.data.borrow())?;
+        if !state.initialized {
+            return Err(ProxyError::NotInitialized.into());
+        }
+        if state.authority != *authority_info.key {
+            return Err(ProxyError::InvalidAuthority.into());
+        }
+
+        // Derive and verify history PDA
+        let (history_pda, history_bump) =
+            Pubkey::find_program_address(&[HistoryHeader::HISTORY_SEED], program_id);
+        if history_pda != *history_info.key {
+            return Err(ProxyError::InvalidHistoryAccount.into());
+        }
+
+        if history_info.data_len() > 0 {
+            return Err(ProxyError::AlreadyInitialized.into());
+        }
+
+        if capacity == 0 {
+            return Err(ProxyError::InvalidHistoryAccount.into());
+        }
+
+        // Create history account, sized for the header plus `capacity` entries
+        let rent = Rent::get()?;
+        let space = HistoryHeader::LEN + (capacity as usize) * HistoryEntry::LEN;
+        let lamports = rent.minimum_balance(space);
+
+        let create_account_ix = system_instruction::create_account(
+            payer_info.key,
+            history_info.key,
+            lamports,
+            space as u64,
+            program_id,
+        );
+
+        invoke_signed(
+            &create_account_ix,
+            &[payer_info.clone(), history_info.clone(), system_program_info.clone()],
+            &[&[HistoryHeader::HISTORY_SEED, &[history_bump]]],
+        )?;
+
+        let header = HistoryHeader {
+            authority: state.authority,
+            entry_count: 0,
+            head: 0,
+            capacity,
+        };
+        header.serialize(&mut *history_info.data.borrow_mut())?;
+
+        msg!("Multiplier history initialized with capacity {}", capacity);
+
+        Ok(())
+    }
+
+    fn process_initialize_scaled_ui_mint(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        initial_multiplier: f64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let authority_info = next_account_info(account_info_iter)?;
+        let state_info = next_account_info(account_info_iter)?;
+        let authority_pda_info = next_account_info(account_info_iter)?;
+        let token_mint_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
+
+        // Verify authority is signer
+        if !authority_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Load and verify state
+        let mut state = ProxyState::try_from_slice(&state_info.data.borrow())?;
+        if !state.initialized {
+            return Err(ProxyError::NotInitialized.into());
+        }
+
+        // Verify authority
+        if state.authority != *authority_info.key {
+            return Err(ProxyError::InvalidAuthority.into());
+        }
+
+        // A lone authority signature must never be able to repoint the proxy
+        // at a different mint once governance has moved to a multisig quorum
+        if state.multisig_enabled {
+            return Err(ProxyError::InvalidAuthority.into());
+        }
+
+        // Verify token program matches the one configured at Initialize
+        if state.token_program != *token_program_info.key {
+            return Err(ProxyError::InvalidTokenProgram.into());
+        }
+
+        // Verify mint is owned by the configured token program
+        if token_mint_info.owner != token_program_info.key {
+            return Err(ProxyError::InvalidMint.into());
+        }
+
+        // Verify multiplier
+        try_validate_multiplier(initial_multiplier)?;
+
+        // Verify authority PDA
+        let (authority_pda, authority_bump) = Pubkey::find_program_address(
+            &[ProxyState::AUTHORITY_SEED],
+            program_id,
+        );
+        if authority_pda != *authority_pda_info.key || authority_bump != state.bump {
+            return Err(ProxyError::InvalidPDA.into());
+        }
+
+        // Create the Scaled UI Amount extension Initialize instruction, making
+        // our authority PDA the mint's multiplier authority
+        let init_ix = token_2022_helpers::initialize_scaled_ui(
+            token_program_info.key,
+            token_mint_info.key,
+            authority_pda_info.key,
+            initial_multiplier,
+        )?;
+
+        invoke(&init_ix, std::slice::from_ref(token_mint_info))?;
+
+        state.token_mint = *token_mint_info.key;
+        state.current_multiplier = initial_multiplier;
+        state.serialize(&mut *state_info.data.borrow_mut())?;
+
+        msg!(
+            "Scaled UI Amount extension initialized for mint {} with multiplier {}",
+            token_mint_info.key,
+            initial_multiplier
+        );
+
         Ok(())
     }
 
@@ -215,11 +653,337 @@ impl Processor {
             return Err(ProxyError::InvalidAuthority.into());
         }
 
-        // Update authority
-        state.authority = new_authority;
+        // Record the nomination; it only takes effect once the nominee accepts
+        state.pending_authority = new_authority;
+        state.serialize(&mut *state_info.data.borrow_mut())?;
+
+        msg!("Authority handoff to {} nominated, awaiting acceptance", new_authority);
+
+        Ok(())
+    }
+
+    fn process_update_guardrails(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        max_relative_change_bps: u16,
+        min_update_interval_secs: i64,
+        monotonic_only: bool,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let authority_info = next_account_info(account_info_iter)?;
+        let state_info = next_account_info(account_info_iter)?;
+
+        // Verify authority is signer
+        if !authority_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Load and verify state
+        let mut state = ProxyState::try_from_slice(&state_info.data.borrow())?;
+        if !state.initialized {
+            return Err(ProxyError::NotInitialized.into());
+        }
+
+        // Verify authority
+        if state.authority != *authority_info.key {
+            return Err(ProxyError::InvalidAuthority.into());
+        }
+
+        state.max_relative_change_bps = max_relative_change_bps;
+        state.min_update_interval_secs = min_update_interval_secs;
+        state.monotonic_only = monotonic_only;
+        state.serialize(&mut *state_info.data.borrow_mut())?;
+
+        msg!(
+            "Guardrails updated: max_relative_change_bps={} min_update_interval_secs={} monotonic_only={}",
+            max_relative_change_bps,
+            min_update_interval_secs,
+            monotonic_only
+        );
+
+        Ok(())
+    }
+
+    fn process_accept_authority(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let pending_authority_info = next_account_info(account_info_iter)?;
+        let state_info = next_account_info(account_info_iter)?;
+
+        // Verify the nominee is signing
+        if !pending_authority_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Load and verify state
+        let mut state = ProxyState::try_from_slice(&state_info.data.borrow())?;
+        if !state.initialized {
+            return Err(ProxyError::NotInitialized.into());
+        }
+
+        // Verify there is a pending nomination
+        if state.pending_authority == Pubkey::default() {
+            return Err(ProxyError::NoPendingAuthority.into());
+        }
+
+        // Verify the signer is the exact nominee
+        if state.pending_authority != *pending_authority_info.key {
+            return Err(ProxyError::InvalidAuthority.into());
+        }
+
+        let previous_authority = state.authority;
+        state.authority = state.pending_authority;
+        state.pending_authority = Pubkey::default();
+        state.serialize(&mut *state_info.data.borrow_mut())?;
+
+        msg!("Authority accepted by {}, previously {}", state.authority, previous_authority);
+
+        Ok(())
+    }
+
+    fn enforce_guardrails(state: &ProxyState, new_multiplier: f64, now: i64) -> ProgramResult {
+        // Enforce the maximum relative change guardrail, if configured
+        if state.max_relative_change_bps > 0 {
+            let relative_change_bps = ((new_multiplier - state.current_multiplier).abs()
+                / state.current_multiplier)
+                * 10_000.0;
+            if relative_change_bps > state.max_relative_change_bps as f64 {
+                return Err(ProxyError::MultiplierChangeTooLarge.into());
+            }
+        }
+
+        // Enforce the minimum update interval guardrail against the real
+        // on-chain clock, if configured. `effective_timestamp` is caller
+        // supplied (it schedules when the mint's multiplier takes effect),
+        // so throttling against it instead of `now` would let a caller defeat
+        // the interval entirely by just incrementing its declared value.
+        if state.min_update_interval_secs > 0
+            && now < state.last_update_timestamp + state.min_update_interval_secs
+        {
+            return Err(ProxyError::UpdateTooSoon.into());
+        }
+
+        // Enforce monotonic mode, if configured
+        if state.monotonic_only && new_multiplier < state.current_multiplier {
+            return Err(ProxyError::MultiplierChangeTooLarge.into());
+        }
+
+        Ok(())
+    }
+
+    fn process_initialize_oracle_config(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        threshold: u8,
+        oracles: Vec<Pubkey>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let payer_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+        let state_info = next_account_info(account_info_iter)?;
+        let oracle_config_info = next_account_info(account_info_iter)?;
+        let system_program_info = next_account_info(account_info_iter)?;
+
+        if !payer_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if !authority_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Load and verify state
+        let state = ProxyState::try_from_slice(&state_info.data.borrow())?;
+        if !state.initialized {
+            return Err(ProxyError::NotInitialized.into());
+        }
+        if state.authority != *authority_info.key {
+            return Err(ProxyError::InvalidAuthority.into());
+        }
+
+        // A lone authority signature must never be able to stand up an oracle
+        // set it controls and bypass the multisig quorum via SubmitAttestedMultiplier
+        if state.multisig_enabled {
+            return Err(ProxyError::InvalidAuthority.into());
+        }
+
+        // Reject configurations that could never reach quorum, and duplicate oracles
+        if threshold == 0
+            || oracles.is_empty()
+            || (threshold as usize) > oracles.len()
+            || oracles.len() > OracleConfig::MAX_ORACLES
+        {
+            return Err(ProxyError::InsufficientAttestations.into());
+        }
+        for i in 0..oracles.len() {
+            for j in (i + 1)..oracles.len() {
+                if oracles[i] == oracles[j] {
+                    return Err(ProxyError::BadAttestation.into());
+                }
+            }
+        }
+
+        // Derive and verify oracle config PDA
+        let (oracle_config_pda, oracle_config_bump) =
+            Pubkey::find_program_address(&[OracleConfig::ORACLE_SEED], program_id);
+        if oracle_config_pda != *oracle_config_info.key {
+            return Err(ProxyError::InvalidPDA.into());
+        }
+        if oracle_config_info.data_len() > 0 {
+            return Err(ProxyError::AlreadyInitialized.into());
+        }
+
+        // Create and size the oracle config account
+        let rent = Rent::get()?;
+        let space = OracleConfig::space(oracles.len());
+        let lamports = rent.minimum_balance(space);
+
+        let create_account_ix = system_instruction::create_account(
+            payer_info.key,
+            oracle_config_info.key,
+            lamports,
+            space as u64,
+            program_id,
+        );
+
+        invoke_signed(
+            &create_account_ix,
+            &[payer_info.clone(), oracle_config_info.clone(), system_program_info.clone()],
+            &[&[OracleConfig::ORACLE_SEED, &[oracle_config_bump]]],
+        )?;
+
+        let oracle_count = oracles.len();
+        let config = OracleConfig {
+            threshold,
+            last_nonce: 0,
+            oracles,
+        };
+        config.serialize(&mut *oracle_config_info.data.borrow_mut())?;
+
+        msg!("Oracle config initialized with threshold {} of {} oracles", threshold, oracle_count);
+
+        Ok(())
+    }
+
+    fn process_submit_attested_multiplier(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        multiplier: f64,
+        effective_timestamp: i64,
+        nonce: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let instructions_sysvar_info = next_account_info(account_info_iter)?;
+        let state_info = next_account_info(account_info_iter)?;
+        let authority_pda_info = next_account_info(account_info_iter)?;
+        let token_mint_info = next_account_info(account_info_iter)?;
+        let token_program_info = next_account_info(account_info_iter)?;
+        let oracle_config_info = next_account_info(account_info_iter)?;
+
+        if *instructions_sysvar_info.key != INSTRUCTIONS_SYSVAR_ID {
+            return Err(ProxyError::BadAttestation.into());
+        }
+
+        // Load and verify state
+        let mut state = ProxyState::try_from_slice(&state_info.data.borrow())?;
+        if !state.initialized {
+            return Err(ProxyError::NotInitialized.into());
+        }
+
+        // Oracle attestation is a single-key-equivalent path (whoever controls
+        // the configured oracles controls the rebase), so it must not be
+        // usable to bypass a multisig quorum once one is enabled
+        if state.multisig_enabled {
+            return Err(ProxyError::InvalidAuthority.into());
+        }
+
+        // Verify token mint and token program match what was configured at Initialize
+        if state.token_mint != *token_mint_info.key {
+            return Err(ProxyError::InvalidMint.into());
+        }
+        if state.token_program != *token_program_info.key {
+            return Err(ProxyError::InvalidTokenProgram.into());
+        }
+
+        // Verify multiplier and the configured guardrails against the real
+        // on-chain clock
+        try_validate_multiplier(multiplier)?;
+        let now = Clock::get()?.unix_timestamp;
+        Self::enforce_guardrails(&state, multiplier, now)?;
+
+        // Load and verify oracle config
+        let (oracle_config_pda, _) =
+            Pubkey::find_program_address(&[OracleConfig::ORACLE_SEED], program_id);
+        if oracle_config_pda != *oracle_config_info.key {
+            return Err(ProxyError::InvalidPDA.into());
+        }
+        let mut oracle_config =
+            OracleConfig::try_from_slice(&oracle_config_info.data.borrow())?;
+
+        // Enforce strictly-increasing nonce to prevent replay
+        if nonce <= oracle_config.last_nonce {
+            return Err(ProxyError::StaleNonce.into());
+        }
+
+        // Reconstruct the canonical message and count matching oracle attestations
+        let mut message = Vec::with_capacity(8 + 8 + 8);
+        message.extend_from_slice(&multiplier.to_le_bytes());
+        message.extend_from_slice(&effective_timestamp.to_le_bytes());
+        message.extend_from_slice(&nonce.to_le_bytes());
+
+        let attestations = ed25519_helpers::count_verified_attestations(
+            instructions_sysvar_info,
+            &message,
+            &oracle_config.oracles,
+        )?;
+        if attestations < oracle_config.threshold {
+            return Err(ProxyError::InsufficientAttestations.into());
+        }
+
+        // Verify authority PDA
+        let (authority_pda, authority_bump) = Pubkey::find_program_address(
+            &[ProxyState::AUTHORITY_SEED],
+            program_id,
+        );
+        if authority_pda != *authority_pda_info.key || authority_bump != state.bump {
+            return Err(ProxyError::InvalidPDA.into());
+        }
+
+        // Create the update multiplier instruction using our helper
+        let update_ix = token_2022_helpers::update_multiplier(
+            token_program_info.key,
+            token_mint_info.key,
+            authority_pda_info.key,
+            multiplier,
+            effective_timestamp,
+        )?;
+
+        // Invoke with PDA signer
+        invoke_signed(
+            &update_ix,
+            &[token_mint_info.clone(), authority_pda_info.clone()],
+            &[&[ProxyState::AUTHORITY_SEED, &[state.bump]]],
+        )?;
+
+        msg!(
+            "Updated multiplier to {} effective at timestamp {} via oracle attestation (nonce {})",
+            multiplier,
+            effective_timestamp,
+            nonce
+        );
+
+        oracle_config.last_nonce = nonce;
+        oracle_config.serialize(&mut *oracle_config_info.data.borrow_mut())?;
+
+        state.current_multiplier = multiplier;
+        state.last_update_timestamp = now;
         state.serialize(&mut *state_info.data.borrow_mut())?;
 
-        msg!("Authority updated from {} to {}", authority_info.key, new_authority);
+        // Append an audit entry to the history ring buffer, if one was supplied
+        if let Some(history_info) = account_info_iter.next() {
+            Self::record_history_entry(program_id, history_info, multiplier, effective_timestamp)?;
+        }
 
         Ok(())
     }
@@ -231,4 +995,103 @@ fn try_validate_multiplier(multiplier: f64) -> ProgramResult {
     } else {
         Err(ProxyError::InvalidMultiplier.into())
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_state(
+        current_multiplier: f64,
+        last_update_timestamp: i64,
+        max_relative_change_bps: u16,
+        min_update_interval_secs: i64,
+        monotonic_only: bool,
+    ) -> ProxyState {
+        ProxyState {
+            initialized: true,
+            authority: Pubkey::default(),
+            token_mint: Pubkey::default(),
+            bump: 0,
+            pending_authority: Pubkey::default(),
+            token_program: Pubkey::default(),
+            current_multiplier,
+            last_update_timestamp,
+            max_relative_change_bps,
+            min_update_interval_secs,
+            monotonic_only,
+            multisig_enabled: false,
+        }
+    }
+
+    #[test]
+    fn try_validate_multiplier_accepts_positive_normal_values() {
+        assert!(try_validate_multiplier(1.0).is_ok());
+        assert!(try_validate_multiplier(0.0001).is_ok());
+        assert!(try_validate_multiplier(f64::MAX).is_ok());
+    }
+
+    #[test]
+    fn try_validate_multiplier_rejects_non_positive_values() {
+        assert!(try_validate_multiplier(0.0).is_err());
+        assert!(try_validate_multiplier(-1.0).is_err());
+    }
+
+    #[test]
+    fn try_validate_multiplier_rejects_non_normal_values() {
+        assert!(try_validate_multiplier(f64::NAN).is_err());
+        assert!(try_validate_multiplier(f64::INFINITY).is_err());
+        assert!(try_validate_multiplier(f64::MIN_POSITIVE / 2.0).is_err()); // subnormal
+    }
+
+    #[test]
+    fn enforce_guardrails_allows_change_at_exact_bps_boundary() {
+        // 1.0 -> 1.5 is exactly 5000 bps; both operands are exact in f64 so
+        // the comparison lands exactly on the boundary, not on either side of it
+        let state = test_state(1.0, 0, 5_000, 0, false);
+        assert!(Processor::enforce_guardrails(&state, 1.5, 0).is_ok());
+    }
+
+    #[test]
+    fn enforce_guardrails_rejects_change_past_bps_boundary() {
+        let state = test_state(1.0, 0, 5_000, 0, false);
+        assert!(Processor::enforce_guardrails(&state, 1.6, 0).is_err());
+    }
+
+    #[test]
+    fn enforce_guardrails_bps_check_disabled_when_zero() {
+        let state = test_state(1.0, 0, 0, 0, false);
+        assert!(Processor::enforce_guardrails(&state, 1_000.0, 0).is_ok());
+    }
+
+    #[test]
+    fn enforce_guardrails_allows_update_at_exact_interval_boundary() {
+        let state = test_state(1.0, 100, 0, 60, false);
+        assert!(Processor::enforce_guardrails(&state, 1.0, 160).is_ok());
+    }
+
+    #[test]
+    fn enforce_guardrails_rejects_update_before_interval_elapsed() {
+        let state = test_state(1.0, 100, 0, 60, false);
+        assert!(Processor::enforce_guardrails(&state, 1.0, 159).is_err());
+    }
+
+    #[test]
+    fn enforce_guardrails_interval_check_disabled_when_zero() {
+        let state = test_state(1.0, 100, 0, 0, false);
+        assert!(Processor::enforce_guardrails(&state, 1.0, 100).is_ok());
+    }
+
+    #[test]
+    fn enforce_guardrails_monotonic_allows_equal_or_increasing() {
+        let state = test_state(1.0, 0, 0, 0, true);
+        assert!(Processor::enforce_guardrails(&state, 1.0, 0).is_ok());
+        assert!(Processor::enforce_guardrails(&state, 1.5, 0).is_ok());
+    }
+
+    #[test]
+    fn enforce_guardrails_monotonic_rejects_decrease() {
+        let state = test_state(1.0, 0, 0, 0, true);
+        assert!(Processor::enforce_guardrails(&state, 0.999, 0).is_err());
+    }
 }
\ No newline at end of file
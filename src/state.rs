@@ -12,14 +12,128 @@ pub struct ProxyState {
     pub token_mint: Pubkey,
     /// PDA bump seed for the authority PDA
     pub bump: u8,
+    /// Authority nominated to take over via `AcceptAuthority`, or the
+    /// default pubkey if no handoff is pending
+    pub pending_authority: Pubkey,
+    /// Token program implementing the Scaled UI Amount interface that the
+    /// proxy is configured to CPI into (e.g. Token-2022 or a compatible fork)
+    pub token_program: Pubkey,
+    /// Multiplier currently in effect, cached on each successful update so
+    /// relative-change guardrails don't need to read back the mint
+    pub current_multiplier: f64,
+    /// On-chain clock time of the last accepted `UpdateMultiplier` or
+    /// `SubmitAttestedMultiplier` call
+    pub last_update_timestamp: i64,
+    /// Maximum allowed relative change per update, in basis points of the
+    /// previous multiplier; 0 disables the check
+    pub max_relative_change_bps: u16,
+    /// Minimum number of seconds required between two updates; 0 disables the check
+    pub min_update_interval_secs: i64,
+    /// When true, `new_multiplier` must never be lower than `current_multiplier`
+    pub monotonic_only: bool,
+    /// When true, `UpdateMultiplier` requires a signer quorum from the
+    /// `MultisigConfig` PDA instead of a single `authority` signature
+    pub multisig_enabled: bool,
 }
 
 impl ProxyState {
-    pub const LEN: usize = 1 + 32 + 32 + 1; // bool + 2 Pubkeys + u8
+    pub const LEN: usize = 1 + 32 + 32 + 1 + 32 + 32 + 8 + 8 + 2 + 8 + 1 + 1; // bool + 4 Pubkeys + u8 + f64 + i64 + u16 + i64 + 2 bools
 
     /// Authority PDA seeds
     pub const AUTHORITY_SEED: &'static [u8] = b"proxy_authority";
-    
-    /// State PDA seeds  
+
+    /// State PDA seeds
     pub const STATE_SEED: &'static [u8] = b"state";
+}
+
+/// Fixed-size header for the on-chain multiplier history account. The
+/// header is followed by a ring buffer of `capacity` [`HistoryEntry`]
+/// records.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct HistoryHeader {
+    /// `ProxyState::authority` at the time the history account was created;
+    /// it is not updated by later authority handoffs
+    pub authority: Pubkey,
+    /// Number of entries written so far, capped at `capacity`
+    pub entry_count: u32,
+    /// Index of the next slot to write, wrapping modulo `capacity`
+    pub head: u32,
+    /// Total number of entries the ring buffer can hold
+    pub capacity: u32,
+}
+
+impl HistoryHeader {
+    pub const LEN: usize = 32 + 4 + 4 + 4; // Pubkey + 3 u32s
+
+    /// History PDA seeds
+    pub const HISTORY_SEED: &'static [u8] = b"multiplier_history";
+}
+
+/// A single recorded multiplier update, written into the history ring buffer
+/// after a successful `UpdateMultiplier` or `SubmitAttestedMultiplier` CPI
+/// when the history account is passed to that call; calls made without it
+/// record nothing.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq)]
+pub struct HistoryEntry {
+    /// Multiplier that was set
+    pub multiplier: f64,
+    /// Unix timestamp the multiplier became effective
+    pub effective_timestamp: i64,
+    /// Slot at which the entry was recorded
+    pub recorded_slot: u64,
+}
+
+impl HistoryEntry {
+    pub const LEN: usize = 8 + 8 + 8; // f64 + i64 + u64
+}
+
+/// Threshold-signature configuration for M-of-N multisig governance of
+/// `UpdateMultiplier`, stored in its own PDA so the signer set can be sized
+/// independently of `ProxyState`. The set is fixed once the account is
+/// created.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct MultisigConfig {
+    /// Number of distinct configured signers required to approve an update
+    pub threshold: u8,
+    /// Pubkeys authorized to co-sign `UpdateMultiplier`
+    pub signers: Vec<Pubkey>,
+}
+
+impl MultisigConfig {
+    /// Upper bound on the number of configured signers
+    pub const MAX_SIGNERS: usize = 16;
+
+    /// Multisig PDA seeds
+    pub const MULTISIG_SEED: &'static [u8] = b"multisig";
+
+    /// Account space required to hold `signer_count` signers
+    pub fn space(signer_count: usize) -> usize {
+        1 + 4 + 32 * signer_count // threshold + vec length prefix + Pubkeys
+    }
+}
+
+/// Configuration for oracle-attestation-driven multiplier updates. Kept in
+/// its own PDA, like [`MultisigConfig`], so the oracle set can be sized
+/// independently of `ProxyState`.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq)]
+pub struct OracleConfig {
+    /// Number of distinct oracle attestations required per update
+    pub threshold: u8,
+    /// Nonce of the last accepted attestation; subsequent ones must be strictly greater
+    pub last_nonce: u64,
+    /// Ed25519 pubkeys authorized to attest multiplier updates
+    pub oracles: Vec<Pubkey>,
+}
+
+impl OracleConfig {
+    /// Upper bound on the number of configured oracles
+    pub const MAX_ORACLES: usize = 16;
+
+    /// Oracle config PDA seeds
+    pub const ORACLE_SEED: &'static [u8] = b"oracle_config";
+
+    /// Account space required to hold `oracle_count` oracles
+    pub fn space(oracle_count: usize) -> usize {
+        1 + 8 + 4 + 32 * oracle_count // threshold + last_nonce + vec length prefix + Pubkeys
+    }
 }
\ No newline at end of file
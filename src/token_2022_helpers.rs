@@ -28,8 +28,11 @@ pub enum ScaledUiAmountMintInstruction {
     UpdateMultiplier = 1,
 }
 
-/// Create an UpdateMultiplier instruction for Token-2022 Scaled UI Amount extension
+/// Create an UpdateMultiplier instruction for the Scaled UI Amount extension,
+/// targeting whichever token program implements the interface (Token-2022 or
+/// a compatible fork).
 pub fn update_multiplier(
+    token_program: &Pubkey,
     mint: &Pubkey,
     authority: &Pubkey,
     multiplier: f64,
@@ -49,7 +52,31 @@ pub fn update_multiplier(
     instruction_data.extend_from_slice(&effective_timestamp.to_le_bytes());
 
     Ok(Instruction {
-        program_id: TOKEN_2022_PROGRAM_ID,
+        program_id: *token_program,
+        accounts,
+        data: instruction_data,
+    })
+}
+
+/// Create an Initialize instruction for the Scaled UI Amount mint extension,
+/// making `authority` the mint's multiplier authority from mint creation.
+pub fn initialize_scaled_ui(
+    token_program: &Pubkey,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    multiplier: f64,
+) -> Result<Instruction, ProgramError> {
+    let accounts = vec![AccountMeta::new(*mint, false)];
+
+    // Format: [main_instruction_type, sub_instruction_type, multiplier_bytes, authority_pubkey]
+    let mut instruction_data = vec![];
+    instruction_data.push(TokenInstruction::ScaledUiAmountExtension as u8);
+    instruction_data.push(ScaledUiAmountMintInstruction::Initialize as u8);
+    instruction_data.extend_from_slice(&multiplier.to_le_bytes());
+    instruction_data.extend_from_slice(&authority.to_bytes());
+
+    Ok(Instruction {
+        program_id: *token_program,
         accounts,
         data: instruction_data,
     })